@@ -0,0 +1,142 @@
+use {
+    crate::{
+        app_error::AppError,
+        configs::custom::{
+            app_custom::AppConfig, i18n_custom::I18nConfig, keymap_custom::KeymapConfig,
+        },
+        enums::{action::Action, event::Event},
+        tui::Tui,
+    },
+    std::time::Duration,
+    tokio::sync::mpsc,
+};
+
+/// `App` owns the `Tui` and drives the outer event/update/draw loop.
+pub struct App {
+    /// The application configuration.
+    app_config: AppConfig,
+    /// The main user interface.
+    tui: Tui,
+    /// The channel actions are sent on, from event handling and from
+    /// components, and received from for `update`.
+    action_tx: mpsc::UnboundedSender<Action>,
+    action_rx: mpsc::UnboundedReceiver<Action>,
+    /// Set once `Action::Quit` is observed, stopping the loop.
+    should_quit: bool,
+}
+
+impl App {
+    /// Create a new instance of the `App` struct.
+    ///
+    /// # Arguments
+    /// * `app_config` - The application configuration.
+    /// * `keymap_config` - The keymap configuration.
+    /// * `i18n_config` - The active i18n configuration.
+    ///
+    /// # Returns
+    /// * `Self` - The new instance of the `App` struct.
+    pub fn new(
+        app_config: AppConfig,
+        keymap_config: KeymapConfig,
+        i18n_config: I18nConfig,
+    ) -> Result<Self, AppError> {
+        let (action_tx, action_rx) = mpsc::unbounded_channel();
+        let mut tui = Tui::new(app_config.clone(), keymap_config, i18n_config);
+        tui.register_action_handler(action_tx.clone())?;
+
+        Ok(App {
+            app_config,
+            tui,
+            action_tx,
+            action_rx,
+            should_quit: false,
+        })
+    }
+
+    /// Run the application until the user quits.
+    ///
+    /// Draws are coalesced to `app_config.frame_rate` and skipped entirely
+    /// when `Tui::needs_redraw` reports nothing changed, so an idle session
+    /// does not keep repainting the terminal. The mouse cursor is hidden or
+    /// shown every tick based on `Tui::should_hide_cursor`.
+    pub async fn run(&mut self, terminal: &mut ratatui::DefaultTerminal) -> Result<(), AppError> {
+        if self.app_config.wants_background_translucency() {
+            // There is no portable way to adjust only the alpha channel of
+            // the terminal's existing background color without first
+            // querying it, so we defer to the user's terminal/compositor
+            // settings instead of risking stomping their theme.
+            tracing::warn!(
+                "background_opacity < 1.0 is set, but this terminal emulator's \
+                 own settings control translucency; tgt does not emit an \
+                 escape sequence for it"
+            );
+        }
+
+        let frame_duration = Duration::from_secs_f64(1.0 / self.app_config.frame_rate);
+        let mut frame_interval = tokio::time::interval(frame_duration);
+        let mut cursor_hidden = false;
+
+        while !self.should_quit {
+            let event = if crossterm::event::poll(Duration::from_millis(0))? {
+                match crossterm::event::read()? {
+                    crossterm::event::Event::Key(key) => {
+                        Some(Event::Key(key.code, key.modifiers))
+                    }
+                    crossterm::event::Event::Mouse(mouse_event) => {
+                        Some(Event::Mouse(mouse_event))
+                    }
+                    crossterm::event::Event::Resize(width, height) => {
+                        Some(Event::Resize(width, height))
+                    }
+                    _ => None,
+                }
+            } else {
+                None
+            };
+
+            if let Some(action) = self.tui.handle_events(event)? {
+                self.action_tx.send(action)?;
+            }
+
+            while let Ok(action) = self.action_rx.try_recv() {
+                if matches!(action, Action::Quit) {
+                    self.should_quit = true;
+                }
+                if let Some(next_action) = self.tui.update(action)? {
+                    self.action_tx.send(next_action)?;
+                }
+            }
+
+            frame_interval.tick().await;
+
+            // A plain time-based tick, so components with a running
+            // animation (e.g. the `StatusBar` activity spinner) can re-arm
+            // `needs_redraw` on their own even while no real event or
+            // action is flowing through the loop.
+            if let Some(action) = self.tui.update(Action::Tick)? {
+                self.action_tx.send(action)?;
+            }
+
+            if self.tui.needs_redraw() {
+                terminal.draw(|frame| {
+                    let area = frame.area();
+                    if let Err(error) = self.tui.draw(frame, area) {
+                        tracing::error!("Failed to draw the terminal: {error}");
+                    }
+                })?;
+            }
+
+            let should_hide = self.tui.should_hide_cursor();
+            if should_hide != cursor_hidden {
+                if should_hide {
+                    terminal.hide_cursor()?;
+                } else {
+                    terminal.show_cursor()?;
+                }
+                cursor_hidden = should_hide;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use {
+    crate::{
+        app_error::AppError,
+        enums::{action::Action, event::Event},
+    },
+    tokio::sync::mpsc::UnboundedSender,
+};
+
+/// Common behavior shared by every piece of the UI that `Tui` manages.
+///
+/// Implementors report whether their visible state changed via the `bool`
+/// returned from `update`/`handle_events`; `Tui` ORs these together into
+/// `needs_redraw` so the outer render loop only draws when something
+/// actually changed.
+pub trait Component {
+    /// Register the sender used to push actions for later processing.
+    ///
+    /// # Arguments
+    /// * `tx` - An unbounded sender that can send actions.
+    fn register_action_handler(
+        &mut self,
+        _tx: UnboundedSender<Action>,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+    /// Translate a terminal event into an action, if any.
+    ///
+    /// # Arguments
+    /// * `event` - An optional event to be processed.
+    ///
+    /// # Returns
+    /// The resulting action, if any, and whether this component's visible
+    /// state changed as a result.
+    fn handle_events(
+        &mut self,
+        _event: Option<Event>,
+    ) -> Result<(Option<Action>, bool), AppError> {
+        Ok((None, false))
+    }
+    /// Update this component's state in response to an action.
+    ///
+    /// # Arguments
+    /// * `action` - An action that may modify the state of the component.
+    ///
+    /// # Returns
+    /// A follow-up action to process, if any, and whether this component's
+    /// visible state changed as a result.
+    fn update(&mut self, _action: Action) -> std::io::Result<(Option<Action>, bool)> {
+        Ok((None, false))
+    }
+    /// Render this component into `area`.
+    ///
+    /// # Arguments
+    /// * `frame` - A mutable reference to the frame to be rendered.
+    /// * `area` - The area to render into.
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame<'_>,
+        area: ratatui::layout::Rect,
+    ) -> std::io::Result<()>;
+    /// Box this component for storage in `Tui`'s component map.
+    fn new_boxed(self) -> Box<dyn Component>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+/// Components that can be focused or unfocused.
+pub trait HandleFocus {
+    /// Mark this component as focused.
+    fn focus(&mut self);
+    /// Mark this component as unfocused.
+    fn unfocus(&mut self);
+}
+
+/// Components that render a condensed layout on narrow terminals.
+pub trait HandleSmallArea {
+    /// Set whether this component should render its smaller layout.
+    ///
+    /// # Arguments
+    /// * `small_area` - Whether the terminal area is small.
+    fn with_small_area(&mut self, small_area: bool);
+}
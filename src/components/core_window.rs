@@ -0,0 +1,111 @@
+use {
+    crate::{
+        app_error::AppError,
+        components::component::{Component, HandleSmallArea},
+        configs::config_theme::style_core_window,
+        enums::{action::Action, event::Event},
+    },
+    ratatui::{
+        layout::Rect,
+        widgets::{block::Block, Borders, Paragraph},
+    },
+    tokio::sync::mpsc::UnboundedSender,
+};
+
+/// `CoreWindow` is a struct that represents the main window of the
+/// application, between the `TitleBar` and the `StatusBar`. It is
+/// responsible for managing the layout and rendering of its content, and for
+/// translating raw terminal events into actions.
+pub struct CoreWindow {
+    /// The name of the `CoreWindow`.
+    name: String,
+    /// An unbounded sender that send action for processing.
+    command_tx: Option<UnboundedSender<Action>>,
+    /// A flag indicating whether the `CoreWindow` should be displayed as a
+    /// smaller version of itself.
+    small_area: bool,
+}
+/// Default implementation for `CoreWindow`.
+impl Default for CoreWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/// Implementation of `CoreWindow`.
+impl CoreWindow {
+    /// Create a new instance of the `CoreWindow` struct.
+    ///
+    /// # Returns
+    /// * `Self` - The new instance of the `CoreWindow` struct.
+    pub fn new() -> Self {
+        CoreWindow {
+            name: "".to_string(),
+            command_tx: None,
+            small_area: false,
+        }
+    }
+    /// Set the name of the `CoreWindow`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `CoreWindow`.
+    ///
+    /// # Returns
+    /// * `Self` - The modified instance of the `CoreWindow`.
+    pub fn with_name(mut self, name: impl AsRef<str>) -> Self {
+        self.name = name.as_ref().to_string();
+        self
+    }
+}
+
+/// Implement the `HandleSmallArea` trait for the `CoreWindow` struct.
+/// This trait allows the `CoreWindow` to display a smaller version of itself
+/// if necessary.
+impl HandleSmallArea for CoreWindow {
+    /// Set the `small_area` flag for the `CoreWindow`.
+    ///
+    /// # Arguments
+    /// * `small_area` - A boolean flag indicating whether the `CoreWindow`
+    ///   should be displayed as a smaller version of itself.
+    fn with_small_area(&mut self, small_area: bool) {
+        self.small_area = small_area;
+    }
+}
+
+/// Implement the `Component` trait for the `CoreWindow` struct.
+impl Component for CoreWindow {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> std::io::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn handle_events(
+        &mut self,
+        event: Option<Event>,
+    ) -> Result<(Option<Action>, bool), AppError> {
+        let dirty = matches!(
+            event,
+            Some(Event::Key(_, _)) | Some(Event::Mouse(_)) | Some(Event::Resize(_, _))
+        );
+        let action = match event {
+            Some(Event::Key(key, modifiers)) => Some(Action::Key(key, modifiers)),
+            Some(Event::Mouse(mouse_event)) => Some(Action::Mouse(mouse_event)),
+            _ => None,
+        };
+        Ok((action, dirty))
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame<'_>, area: Rect) -> std::io::Result<()> {
+        let borders = if self.small_area {
+            Borders::NONE
+        } else {
+            Borders::ALL
+        };
+        let paragraph = Paragraph::new(self.name.as_str())
+            .block(Block::new().borders(borders))
+            .style(style_core_window());
+
+        frame.render_widget(paragraph, area);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+//! The UI components that make up `Tui`.
+
+pub mod component;
+pub mod core_window;
+pub mod status_bar;
+pub mod title_bar;
+
+/// The terminal height, in rows, below which components switch to their
+/// condensed, small-area layout.
+pub const SMALL_AREA_HEIGHT: u16 = 20;
+/// The terminal width, in columns, below which components switch to their
+/// condensed, small-area layout.
+pub const SMALL_AREA_WIDTH: u16 = 80;
@@ -1,13 +1,17 @@
 use {
     crate::{
         components::component::{Component, HandleFocus, HandleSmallArea},
-        configs::config_theme::{
-            style_status_bar, style_status_bar_message_quit_key,
-            style_status_bar_message_quit_text, style_status_bar_press_key_key,
-            style_status_bar_press_key_text, style_status_bar_size_info_numbers,
-            style_status_bar_size_info_text,
+        configs::{
+            config_theme::{
+                style_status_bar, style_status_bar_message_quit_key,
+                style_status_bar_message_quit_text, style_status_bar_press_key_key,
+                style_status_bar_press_key_text, style_status_bar_size_info_numbers,
+                style_status_bar_size_info_text,
+            },
+            custom::{i18n_custom::I18nConfig, keymap_custom::KeymapConfig},
         },
-        enums::{action::Action, event::Event},
+        enums::{action::Action, component_name::ComponentName, event::Event},
+        i18n::{StringId, TString},
     },
     ratatui::{
         layout::{Alignment, Rect},
@@ -17,6 +21,28 @@ use {
     tokio::sync::mpsc::UnboundedSender,
 };
 
+/// The maximum number of keybind hints shown when `small_area` is true, so
+/// the footer line still fits on narrow terminals.
+const MAX_HINTS_SMALL_AREA: usize = 3;
+
+/// The frames cycled through to animate the activity spinner.
+const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+
+/// The number of `Action::Tick`s between rotating which background activity
+/// is shown, when more than one is active and there is room to rotate.
+const ACTIVITY_ROTATE_EVERY: u8 = 8;
+
+/// A long-running background task surfaced in the `StatusBar`, e.g.
+/// connecting to Telegram or uploading media.
+#[derive(Clone, Debug)]
+struct Activity {
+    /// The human-readable description of the task.
+    label: String,
+    /// The task's completion progress, in `[0.0, 1.0]`, or `None` if it is
+    /// indeterminate.
+    progress: Option<f32>,
+}
+
 /// `StatusBar` is a struct that represents a status bar.
 /// It is responsible for managing the layout and rendering of the status bar.
 pub struct StatusBar {
@@ -33,26 +59,50 @@ pub struct StatusBar {
     terminal_area: Rect,
     /// The last key pressed.
     last_key: Event,
+    /// The resolved keymap, used to look up the keybind hints shown for
+    /// whichever component currently has focus.
+    keymap_config: KeymapConfig,
+    /// The component that currently has focus elsewhere in the `Tui`, or
+    /// `None` if nothing is focused. Drives which keybind hints are shown.
+    focused_component: Option<ComponentName>,
+    /// The active i18n configuration, used to translate user-facing labels.
+    i18n: I18nConfig,
+    /// The background tasks currently reported as active, in the order
+    /// they were last set. Keyed by task id.
+    activities: Vec<(String, Activity)>,
+    /// Ticks once per `Action::Tick` while `activities` is non-empty,
+    /// driving the spinner animation and the task rotation when there's
+    /// more than one active task.
+    spinner_tick: u8,
 }
 /// Default implementation for `StatusBar`.
 impl Default for StatusBar {
     fn default() -> Self {
-        Self::new()
+        Self::new(KeymapConfig::default(), I18nConfig::default())
     }
 }
 /// Implementation of `StatusBar`.
 impl StatusBar {
     /// Create a new instance of the `StatusBar` struct.
     ///
+    /// # Arguments
+    /// * `keymap_config` - The resolved keymap, used to render context-aware
+    ///   keybind hints for the currently focused component.
+    /// * `i18n` - The active i18n configuration, used to translate the
+    ///   labels rendered by `draw`.
+    ///
     /// # Returns
     /// * `Self` - The new instance of the `StatusBar` struct.
-    pub fn new() -> Self {
+    pub fn new(keymap_config: KeymapConfig, i18n: I18nConfig) -> Self {
         let command_tx = None;
         let name = "".to_string();
         let small_area = false;
         let terminal_area = Rect::default();
         let last_key = Event::Unknown;
         let focused = false;
+        let focused_component = None;
+        let activities = Vec::new();
+        let spinner_tick = 0;
 
         StatusBar {
             command_tx,
@@ -60,6 +110,11 @@ impl StatusBar {
             small_area,
             terminal_area,
             last_key,
+            keymap_config,
+            focused_component,
+            i18n,
+            activities,
+            spinner_tick,
             focused,
         }
     }
@@ -74,6 +129,89 @@ impl StatusBar {
         self.name = name.as_ref().to_string();
         self
     }
+    /// Resolve the ordered `(key_string, action_label)` hints to show for
+    /// the currently focused component, using the `CoreWindow` bindings when
+    /// nothing is focused, and falling back to fixed `q` / `ctrl+c` quit
+    /// hints when the keymap has no entries for that component.
+    /// Labels are translated against the active i18n configuration.
+    ///
+    /// # Returns
+    /// * `Vec<(String, String)>` - The ordered keybind hints, one entry per
+    ///   bound action, each showing the first key it is bound to.
+    fn keybind_hints(&self) -> Vec<(String, String)> {
+        let component = self
+            .focused_component
+            .clone()
+            .unwrap_or(ComponentName::CoreWindow);
+        let hints = self
+            .keymap_config
+            .get_keybindings_for_component(&component, &self.i18n);
+        if hints.is_empty() {
+            let quit = TString::Id(StringId::Quit)
+                .resolve(&self.i18n)
+                .into_owned();
+            vec![("q".to_string(), quit.clone()), ("ctrl+c".to_string(), quit)]
+        } else {
+            hints
+        }
+    }
+    /// Set or replace the activity reported for `id`.
+    ///
+    /// # Arguments
+    /// * `id` - The task id. Updating an existing id moves it to the back,
+    ///   so `activities` stays ordered by most-recently-set.
+    /// * `label` - The human-readable description of the task.
+    /// * `progress` - The task's completion progress, or `None` if
+    ///   indeterminate.
+    fn set_activity(&mut self, id: String, label: String, progress: Option<f32>) {
+        self.activities.retain(|(task_id, _)| *task_id != id);
+        self.activities.push((id, Activity { label, progress }));
+    }
+    /// Remove the activity reported for `id`, if any.
+    ///
+    /// # Arguments
+    /// * `id` - The task id to clear.
+    fn clear_activity(&mut self, id: &str) {
+        self.activities.retain(|(task_id, _)| task_id != id);
+    }
+    /// Build the spans rendering the current activity indicator, or `None`
+    /// when no background task is active.
+    ///
+    /// When `small_area` is true, only the most recently set task is shown,
+    /// with a `(+N)` suffix for any others. Otherwise, tasks rotate every
+    /// `ACTIVITY_ROTATE_EVERY` ticks.
+    fn activity_spans(&self) -> Option<Vec<Span<'static>>> {
+        if self.activities.is_empty() {
+            return None;
+        }
+
+        let spinner =
+            SPINNER_FRAMES[self.spinner_tick as usize % SPINNER_FRAMES.len()];
+        let (activity, overflow) = if self.small_area {
+            let (_, activity) =
+                self.activities.last().expect("checked non-empty above");
+            (activity, self.activities.len() - 1)
+        } else {
+            let index = (self.spinner_tick as usize / ACTIVITY_ROTATE_EVERY as usize)
+                % self.activities.len();
+            (&self.activities[index].1, 0)
+        };
+
+        let mut spans = vec![
+            Span::raw(format!("{spinner} ")),
+            Span::raw(activity.label.clone()),
+        ];
+        if let Some(progress) = activity.progress {
+            spans.push(Span::raw(format!(
+                " {:.0}%",
+                (progress * 100.0).clamp(0.0, 100.0)
+            )));
+        }
+        if overflow > 0 {
+            spans.push(Span::raw(format!(" (+{overflow})")));
+        }
+        Some(spans)
+    }
 }
 
 /// Implement the `HandleFocus` trait for the `StatusBar` struct.
@@ -110,42 +248,107 @@ impl Component for StatusBar {
         Ok(())
     }
 
-    fn update(&mut self, action: Action) {
+    fn update(&mut self, action: Action) -> std::io::Result<(Option<Action>, bool)> {
+        let mut dirty = false;
         match action {
             Action::UpdateArea(area) => {
                 self.terminal_area = area;
+                dirty = true;
             }
             Action::Key(key, modifiers) => {
                 self.last_key = Event::Key(key, modifiers);
+                dirty = true;
+            }
+            Action::FocusComponent(component_name) => {
+                self.focused_component = Some(component_name);
+                dirty = true;
+            }
+            Action::UnfocusComponent => {
+                self.focused_component = None;
+                dirty = true;
+            }
+            Action::SetActivity {
+                id,
+                label,
+                progress,
+            } => {
+                self.set_activity(id, label, progress);
+                dirty = true;
+            }
+            Action::ClearActivity { id } => {
+                self.clear_activity(&id);
+                dirty = true;
+            }
+            Action::Tick => {
+                if !self.activities.is_empty() {
+                    self.spinner_tick = self.spinner_tick.wrapping_add(1);
+                }
             }
             _ => {}
         }
+        // Keep animating the spinner while a background task is active,
+        // even if nothing else changed this tick.
+        dirty |= !self.activities.is_empty();
+        Ok((None, dirty))
     }
 
     fn draw(&mut self, frame: &mut ratatui::Frame<'_>, area: Rect) -> std::io::Result<()> {
-        let text = vec![Line::from(vec![
-            Span::styled("Press ", style_status_bar_message_quit_text()),
-            Span::styled("q ", style_status_bar_message_quit_key()),
-            Span::styled("or ", style_status_bar_message_quit_text()),
-            Span::styled("ctrl+c ", style_status_bar_message_quit_key()),
-            Span::styled("to quit", style_status_bar_message_quit_text()),
-            //
-            Span::raw("     "),
-            Span::styled("Press key: ", style_status_bar_press_key_text()),
-            Span::styled(self.last_key.to_string(), style_status_bar_press_key_key()),
-            //
-            Span::raw("     "),
-            Span::styled("Size: ", style_status_bar_size_info_text()),
-            Span::styled(
-                self.terminal_area.width.to_string(),
-                style_status_bar_size_info_numbers(),
-            ),
-            Span::styled(" x ", style_status_bar_size_info_text()),
-            Span::styled(
-                self.terminal_area.height.to_string(),
-                style_status_bar_size_info_numbers(),
-            ),
-        ])];
+        let hints = self.keybind_hints();
+        let max_hints = if self.small_area {
+            MAX_HINTS_SMALL_AREA
+        } else {
+            hints.len()
+        };
+
+        let mut spans = Vec::new();
+        if let Some(activity_spans) = self.activity_spans() {
+            spans.extend(activity_spans);
+            spans.push(Span::raw("     "));
+        }
+        for (i, (key, label)) in hints.iter().take(max_hints).enumerate() {
+            if i > 0 {
+                spans.push(Span::raw("     "));
+            }
+            spans.push(Span::styled(
+                format!("{label} "),
+                style_status_bar_message_quit_text(),
+            ));
+            spans.push(Span::styled(
+                key.to_string(),
+                style_status_bar_message_quit_key(),
+            ));
+        }
+
+        let press_key_label =
+            TString::Id(StringId::PressKeyLabel).resolve(&self.i18n);
+        let size_label = TString::Id(StringId::SizeLabel).resolve(&self.i18n);
+
+        spans.push(Span::raw("     "));
+        spans.push(Span::styled(
+            press_key_label,
+            style_status_bar_press_key_text(),
+        ));
+        spans.push(Span::styled(
+            self.last_key.to_string(),
+            style_status_bar_press_key_key(),
+        ));
+
+        spans.push(Span::raw("     "));
+        spans.push(Span::styled(
+            size_label,
+            style_status_bar_size_info_text(),
+        ));
+        spans.push(Span::styled(
+            self.terminal_area.width.to_string(),
+            style_status_bar_size_info_numbers(),
+        ));
+        spans.push(Span::styled(" x ", style_status_bar_size_info_text()));
+        spans.push(Span::styled(
+            self.terminal_area.height.to_string(),
+            style_status_bar_size_info_numbers(),
+        ));
+
+        let text = vec![Line::from(spans)];
 
         let paragraph = Paragraph::new(text)
             .block(Block::new().title(self.name.as_str()).borders(Borders::ALL))
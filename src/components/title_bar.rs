@@ -0,0 +1,71 @@
+use {
+    crate::{
+        components::component::Component,
+        configs::config_theme::style_title_bar,
+        enums::action::Action,
+    },
+    ratatui::{
+        layout::{Alignment, Rect},
+        widgets::{block::Block, Borders, Paragraph},
+    },
+    tokio::sync::mpsc::UnboundedSender,
+};
+
+/// `TitleBar` is a struct that represents the title bar shown above the
+/// `CoreWindow`. It is responsible for managing the layout and rendering of
+/// the title bar.
+pub struct TitleBar {
+    /// The name of the `TitleBar`.
+    name: String,
+    /// An unbounded sender that send action for processing.
+    command_tx: Option<UnboundedSender<Action>>,
+}
+/// Default implementation for `TitleBar`.
+impl Default for TitleBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/// Implementation of `TitleBar`.
+impl TitleBar {
+    /// Create a new instance of the `TitleBar` struct.
+    ///
+    /// # Returns
+    /// * `Self` - The new instance of the `TitleBar` struct.
+    pub fn new() -> Self {
+        TitleBar {
+            name: "".to_string(),
+            command_tx: None,
+        }
+    }
+    /// Set the name of the `TitleBar`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `TitleBar`.
+    ///
+    /// # Returns
+    /// * `Self` - The modified instance of the `TitleBar`.
+    pub fn with_name(mut self, name: impl AsRef<str>) -> Self {
+        self.name = name.as_ref().to_string();
+        self
+    }
+}
+
+/// Implement the `Component` trait for the `TitleBar` struct.
+impl Component for TitleBar {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> std::io::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame<'_>, area: Rect) -> std::io::Result<()> {
+        let paragraph = Paragraph::new(self.name.as_str())
+            .block(Block::new().borders(Borders::ALL))
+            .style(style_title_bar())
+            .alignment(Alignment::Center);
+
+        frame.render_widget(paragraph, area);
+
+        Ok(())
+    }
+}
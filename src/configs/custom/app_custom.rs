@@ -16,8 +16,26 @@ pub struct AppConfig {
     pub mouse_support: bool,
     /// The paste support.
     pub paste_support: bool,
-    /// The frame rate.
+    /// The maximum frame rate. Draws are coalesced to this rate rather than
+    /// happening on every tick; the `Tui` only actually redraws when a
+    /// component reports it changed, a resize occurs, or an animation (e.g.
+    /// the `StatusBar` activity spinner) is in progress.
     pub frame_rate: f64,
+    /// The active UI language, e.g. `"en-US"`. Resolved against the
+    /// matching `I18nConfig` to translate user-facing strings.
+    pub language: String,
+    /// The background opacity, in `[0.0, 1.0]`; `1.0` is fully opaque.
+    ///
+    /// No terminal emulator exposes a standard escape sequence for setting
+    /// only the alpha channel of its existing background color, so tgt
+    /// cannot apply this itself. `wants_background_translucency` reports
+    /// when this is set below `1.0` so the app can tell the user to
+    /// configure translucency in their terminal emulator or compositor
+    /// instead.
+    pub background_opacity: f64,
+    /// Whether to hide the mouse cursor while a key is being typed,
+    /// restoring it on the next mouse-move event.
+    pub hide_mouse_when_typing: bool,
 }
 /// The application configuration implementation.
 impl AppConfig {
@@ -30,6 +48,23 @@ impl AppConfig {
             &configs::custom::default_config_app_file_path()?,
         ))
     }
+    /// Whether the terminal should be asked to render a translucent
+    /// background for this session.
+    ///
+    /// There is no portable way to adjust only the alpha channel of a
+    /// terminal's existing background color via escape sequence without
+    /// first querying it (and not every terminal answers OSC 11 queries),
+    /// so `background_opacity` is surfaced here for callers to act on
+    /// (e.g. deferring to the user's terminal/compositor settings) rather
+    /// than emitting an escape sequence that would overwrite their theme's
+    /// background color outright.
+    ///
+    /// # Returns
+    /// `true` if `background_opacity` asks for anything less than fully
+    /// opaque.
+    pub fn wants_background_translucency(&self) -> bool {
+        self.background_opacity < 1.0
+    }
 }
 /// The implementation of the configuration file for the application.
 impl ConfigFile for AppConfig {
@@ -56,6 +91,15 @@ impl ConfigFile for AppConfig {
                 if let Some(frame_rate) = other.frame_rate {
                     self.frame_rate = frame_rate;
                 }
+                if let Some(language) = other.language {
+                    self.language = language;
+                }
+                if let Some(background_opacity) = other.background_opacity {
+                    self.background_opacity = background_opacity.clamp(0.0, 1.0);
+                }
+                if let Some(hide_mouse_when_typing) = other.hide_mouse_when_typing {
+                    self.hide_mouse_when_typing = hide_mouse_when_typing;
+                }
                 self.clone()
             }
         }
@@ -75,6 +119,9 @@ impl From<AppRaw> for AppConfig {
             mouse_support: raw.mouse_support.unwrap(),
             paste_support: raw.paste_support.unwrap(),
             frame_rate: raw.frame_rate.unwrap(),
+            language: raw.language.unwrap_or_else(|| "en-US".to_string()),
+            background_opacity: raw.background_opacity.unwrap_or(1.0).clamp(0.0, 1.0),
+            hide_mouse_when_typing: raw.hide_mouse_when_typing.unwrap_or(false),
         }
     }
 }
@@ -92,6 +139,9 @@ mod tests {
         assert!(app_config.mouse_support);
         assert!(app_config.paste_support);
         assert_eq!(app_config.frame_rate, 60.0);
+        assert_eq!(app_config.language, "en-US");
+        assert_eq!(app_config.background_opacity, 1.0);
+        assert!(!app_config.hide_mouse_when_typing);
     }
 
     #[test]
@@ -100,11 +150,61 @@ mod tests {
             mouse_support: Some(true),
             paste_support: Some(true),
             frame_rate: Some(30.0),
+            language: Some("en-US".to_string()),
+            background_opacity: Some(0.8),
+            hide_mouse_when_typing: Some(true),
         };
         let app_config = AppConfig::from(app_raw);
         assert!(app_config.mouse_support);
         assert!(app_config.paste_support);
         assert_eq!(app_config.frame_rate, 30.0);
+        assert_eq!(app_config.language, "en-US");
+        assert_eq!(app_config.background_opacity, 0.8);
+        assert!(app_config.hide_mouse_when_typing);
+    }
+
+    #[test]
+    fn test_app_config_from_raw_clamps_background_opacity() {
+        let app_raw = AppRaw {
+            mouse_support: Some(true),
+            paste_support: Some(true),
+            frame_rate: Some(30.0),
+            language: Some("en-US".to_string()),
+            background_opacity: Some(1.5),
+            hide_mouse_when_typing: Some(false),
+        };
+        let app_config = AppConfig::from(app_raw);
+        assert_eq!(app_config.background_opacity, 1.0);
+    }
+
+    #[test]
+    fn test_app_config_from_raw_defaults_missing_language_opacity_and_hide_mouse() {
+        let app_raw = AppRaw {
+            mouse_support: Some(true),
+            paste_support: Some(true),
+            frame_rate: Some(30.0),
+            language: None,
+            background_opacity: None,
+            hide_mouse_when_typing: None,
+        };
+        let app_config = AppConfig::from(app_raw);
+        assert_eq!(app_config.language, "en-US");
+        assert_eq!(app_config.background_opacity, 1.0);
+        assert!(!app_config.hide_mouse_when_typing);
+    }
+
+    #[test]
+    fn test_wants_background_translucency_opaque() {
+        let mut app_config = AppConfig::default();
+        app_config.background_opacity = 1.0;
+        assert!(!app_config.wants_background_translucency());
+    }
+
+    #[test]
+    fn test_wants_background_translucency_translucent() {
+        let mut app_config = AppConfig::default();
+        app_config.background_opacity = 0.5;
+        assert!(app_config.wants_background_translucency());
     }
 
     #[test]
@@ -113,20 +213,29 @@ mod tests {
             mouse_support: Some(true),
             paste_support: Some(true),
             frame_rate: Some(60.0),
+            language: Some("en-US".to_string()),
+            background_opacity: Some(1.0),
+            hide_mouse_when_typing: Some(false),
         });
         let app_raw = AppRaw {
             mouse_support: Some(false),
             paste_support: Some(false),
             frame_rate: None,
+            language: None,
+            background_opacity: Some(-0.5),
+            hide_mouse_when_typing: Some(true),
         };
         app_config = app_config.merge(Some(app_raw));
         assert!(!app_config.mouse_support);
         assert!(!app_config.paste_support);
         assert_eq!(app_config.frame_rate, 60.0);
+        assert_eq!(app_config.language, "en-US");
+        assert_eq!(app_config.background_opacity, 0.0);
+        assert!(app_config.hide_mouse_when_typing);
     }
 
     #[test]
     fn test_app_config_override_fields() {
         assert!(AppConfig::override_fields());
     }
-}
\ No newline at end of file
+}
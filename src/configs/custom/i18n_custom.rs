@@ -0,0 +1,69 @@
+use {
+    crate::{
+        app_error::AppError,
+        configs::{self, config_file::ConfigFile, config_type::ConfigType, raw::i18n_raw::I18nRaw},
+        i18n::StringId,
+    },
+    std::{collections::HashMap, path::Path},
+};
+
+#[derive(Clone, Debug, Default)]
+/// The i18n (localization) configuration: the active language and its
+/// resolved `StringId::key` -> translation table.
+pub struct I18nConfig {
+    /// The active language, e.g. `"en-US"`.
+    pub language: String,
+    /// The resolved translations for `language`, keyed by `StringId::key`.
+    pub translations: HashMap<String, String>,
+}
+/// The i18n configuration implementation.
+impl I18nConfig {
+    /// Get the default i18n configuration.
+    ///
+    /// # Returns
+    /// The default i18n configuration.
+    pub fn default_result() -> Result<Self, AppError> {
+        configs::deserialize_to_config_into::<I18nRaw, Self>(Path::new(
+            &configs::custom::default_config_i18n_file_path()?,
+        ))
+    }
+    /// Translate the given string id against the loaded table.
+    ///
+    /// # Arguments
+    /// * `id` - The string id to translate.
+    ///
+    /// # Returns
+    /// * `Option<&str>` - The translated text, borrowed from the table
+    ///   without allocating, or `None` if `language` has no entry for `id`;
+    ///   callers fall back to `id.fallback()` in that case.
+    pub fn translate(&self, id: StringId) -> Option<&str> {
+        self.translations.get(id.key()).map(String::as_str)
+    }
+}
+/// The implementation of the configuration file for the i18n configuration.
+impl ConfigFile for I18nConfig {
+    type Raw = I18nRaw;
+
+    fn get_type() -> ConfigType {
+        ConfigType::I18n
+    }
+
+    fn override_fields() -> bool {
+        true
+    }
+
+    fn merge(&mut self, other: Option<Self::Raw>) -> Self {
+        match other {
+            None => self.clone(),
+            Some(other) => {
+                if let Some(language) = other.language {
+                    self.language = language;
+                }
+                if let Some(translations) = other.translations {
+                    self.translations.extend(translations);
+                }
+                self.clone()
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+/// The raw, possibly-partial application configuration as read from the
+/// user's config file.
+pub struct AppRaw {
+    /// The mouse support.
+    pub mouse_support: Option<bool>,
+    /// The paste support.
+    pub paste_support: Option<bool>,
+    /// The frame rate.
+    pub frame_rate: Option<f64>,
+    /// The active UI language, e.g. `"en-US"`.
+    pub language: Option<String>,
+    /// The background opacity, in `[0.0, 1.0]`.
+    pub background_opacity: Option<f64>,
+    /// Whether to hide the mouse cursor while a key is being typed.
+    pub hide_mouse_when_typing: Option<bool>,
+}
@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+/// The raw, possibly-partial i18n configuration as read from a per-language
+/// TOML file (e.g. `en-US.toml`).
+pub struct I18nRaw {
+    /// The language this table is for, e.g. `"en-US"`.
+    pub language: Option<String>,
+    /// The id key -> translated text map for `language`.
+    pub translations: Option<HashMap<String, String>>,
+}
@@ -0,0 +1,8 @@
+//! Localization support for user-facing UI strings.
+//!
+//! String ids and their English fallbacks live in [`tstring`]; the table of
+//! translations for the active language is loaded as [`crate::configs::custom::i18n_custom::I18nConfig`].
+
+mod tstring;
+
+pub use tstring::{StringId, TString};
@@ -0,0 +1,81 @@
+use {crate::configs::custom::i18n_custom::I18nConfig, std::borrow::Cow};
+
+/// Identifies a single user-facing string that can be translated.
+///
+/// The variant's `key` is looked up in the active language's translation
+/// table; `fallback` is the English text used when no table is loaded or the
+/// key is missing from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StringId {
+    /// The "Press key: " label in the `StatusBar`.
+    PressKeyLabel,
+    /// The "Size: " label in the `StatusBar`.
+    SizeLabel,
+    /// The `TitleBar` title.
+    TitleBarTitle,
+    /// The `StatusBar` title.
+    StatusBarTitle,
+    /// The fallback "Quit" keybind hint shown when the keymap has no
+    /// bindings for the focused component.
+    Quit,
+}
+
+impl StringId {
+    /// The key this id is looked up under in a language TOML table.
+    pub fn key(&self) -> &'static str {
+        match self {
+            StringId::PressKeyLabel => "press_key_label",
+            StringId::SizeLabel => "size_label",
+            StringId::TitleBarTitle => "title_bar_title",
+            StringId::StatusBarTitle => "status_bar_title",
+            StringId::Quit => "quit",
+        }
+    }
+
+    /// The English text used when no language table is loaded, or the
+    /// active table has no translation for this id.
+    pub fn fallback(&self) -> &'static str {
+        match self {
+            StringId::PressKeyLabel => "Press key: ",
+            StringId::SizeLabel => "Size: ",
+            StringId::TitleBarTitle => "Tgt",
+            StringId::StatusBarTitle => "Status Bar",
+            StringId::Quit => "Quit",
+        }
+    }
+}
+
+/// A user-facing string, either a plain fallback or a translatable id.
+///
+/// `TString` is resolved against an [`I18nConfig`] with [`TString::resolve`],
+/// which never allocates: the result always borrows either a `'static`
+/// fallback or the matching entry in `i18n`'s translation table.
+#[derive(Clone, Copy, Debug)]
+pub enum TString {
+    /// A string with no translation id, rendered as-is.
+    Raw(&'static str),
+    /// A string resolved from the given id against the active language
+    /// table, falling back to `id.fallback()` when missing.
+    Id(StringId),
+}
+
+impl TString {
+    /// Resolve this string against the given i18n configuration.
+    ///
+    /// # Arguments
+    /// * `i18n` - The active i18n configuration to translate against.
+    ///
+    /// # Returns
+    /// * `Cow<'a, str>` - The translated text, borrowed from `i18n`'s
+    ///   translation table without allocating, or the fallback when no
+    ///   translation was found.
+    pub fn resolve<'a>(&self, i18n: &'a I18nConfig) -> Cow<'a, str> {
+        match self {
+            TString::Raw(s) => Cow::Borrowed(s),
+            TString::Id(id) => match i18n.translate(*id) {
+                Some(translated) => Cow::Borrowed(translated),
+                None => Cow::Borrowed(id.fallback()),
+            },
+        }
+    }
+}
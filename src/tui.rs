@@ -6,8 +6,12 @@ use {
             status_bar::StatusBar, title_bar::TitleBar, SMALL_AREA_HEIGHT,
             SMALL_AREA_WIDTH,
         },
-        configs::custom::{app_custom::AppConfig, keymap_custom::KeymapConfig},
+        configs::custom::{
+            app_custom::AppConfig, i18n_custom::I18nConfig,
+            keymap_custom::KeymapConfig,
+        },
         enums::{action::Action, component_name::ComponentName, event::Event},
+        i18n::{StringId, TString},
     },
     ratatui::layout::{Constraint, Direction, Layout, Rect},
     std::{collections::HashMap, io},
@@ -25,18 +29,34 @@ pub struct Tui {
     components: HashMap<ComponentName, Box<dyn Component>>,
     /// The application configuration.
     app_config: AppConfig,
-    #[allow(dead_code)]
-    /// The keymap configuration.
+    /// The keymap configuration. Resolved down into components (e.g.
+    /// `StatusBar`) so they can render context-aware keybind hints.
     keymap_config: KeymapConfig,
+    /// The active i18n configuration. Resolved down into components so
+    /// they can render translated text.
+    i18n_config: I18nConfig,
     /// The name of the component that currently has focus. It is an optional
     /// value because no component may have focus. The focus is a component
     /// inside the `CoreWindow`.
     focused: Option<ComponentName>,
+    /// Whether at least one component reported a state change, a resize was
+    /// observed, or an animation (e.g. the `StatusBar` activity spinner) is
+    /// in progress since the last `draw`. The outer render loop should only
+    /// call `draw` while this is `true`, coalesced to `app_config.frame_rate`.
+    needs_redraw: bool,
+    /// Whether the outer render loop should currently hide the mouse
+    /// cursor, because `app_config.hide_mouse_when_typing` is enabled and
+    /// the last input seen was a key press rather than a mouse move.
+    hide_cursor: bool,
 }
 /// Implement the `Default` trait for the `Tui` struct.
 impl Default for Tui {
     fn default() -> Self {
-        Self::new(AppConfig::default(), KeymapConfig::default())
+        Self::new(
+            AppConfig::default(),
+            KeymapConfig::default(),
+            I18nConfig::default(),
+        )
     }
 }
 /// Implement the `Tui` struct.
@@ -46,14 +66,24 @@ impl Tui {
     /// # Arguments
     /// * `app_config` - The application configuration.
     /// * `keymap_config` - The keymap configuration.
+    /// * `i18n_config` - The active i18n configuration.
     ///
     /// # Returns
     /// * `Self` - The new instance of the `Tui` struct.
-    pub fn new(app_config: AppConfig, keymap_config: KeymapConfig) -> Self {
+    pub fn new(
+        app_config: AppConfig,
+        keymap_config: KeymapConfig,
+        i18n_config: I18nConfig,
+    ) -> Self {
+        let title_bar_title =
+            TString::Id(StringId::TitleBarTitle).resolve(&i18n_config);
+        let status_bar_title =
+            TString::Id(StringId::StatusBarTitle).resolve(&i18n_config);
+
         let components_iter: Vec<(ComponentName, Box<dyn Component>)> = vec![
             (
                 ComponentName::TitleBar,
-                TitleBar::new().with_name("Tgt").new_boxed(),
+                TitleBar::new().with_name(title_bar_title).new_boxed(),
             ),
             (
                 ComponentName::CoreWindow,
@@ -61,7 +91,9 @@ impl Tui {
             ),
             (
                 ComponentName::StatusBar,
-                StatusBar::new().with_name("Status Bar").new_boxed(),
+                StatusBar::new(keymap_config.clone(), i18n_config.clone())
+                    .with_name(status_bar_title)
+                    .new_boxed(),
             ),
         ];
 
@@ -69,15 +101,38 @@ impl Tui {
         let focused = None;
         let components: HashMap<ComponentName, Box<dyn Component>> =
             components_iter.into_iter().collect();
+        // Always draw the first frame.
+        let needs_redraw = true;
+        let hide_cursor = false;
 
         Tui {
             action_tx,
             components,
             keymap_config,
+            i18n_config,
             focused,
             app_config,
+            needs_redraw,
+            hide_cursor,
         }
     }
+    /// Whether the outer render loop should call `draw` right now.
+    ///
+    /// # Returns
+    /// * `bool` - `true` if at least one component changed, a resize was
+    ///   observed, or an animation is in progress since the last `draw`.
+    pub fn needs_redraw(&self) -> bool {
+        self.needs_redraw
+    }
+    /// Whether the outer render loop should currently hide the mouse
+    /// cursor.
+    ///
+    /// # Returns
+    /// * `bool` - `true` while `app_config.hide_mouse_when_typing` is
+    ///   enabled and the user is typing, `false` otherwise.
+    pub fn should_hide_cursor(&self) -> bool {
+        self.hide_cursor
+    }
     /// Register an action handler that can send actions for processing if
     /// necessary.
     ///
@@ -111,10 +166,13 @@ impl Tui {
         &mut self,
         event: Option<Event>,
     ) -> Result<Option<Action>, AppError> {
-        self.components
+        let (action, dirty) = self
+            .components
             .get_mut(&ComponentName::CoreWindow)
             .unwrap()
-            .handle_events(event.clone())
+            .handle_events(event.clone())?;
+        self.needs_redraw |= dirty;
+        Ok(action)
     }
     /// Update the state of the component based on a received action.
     ///
@@ -126,27 +184,46 @@ impl Tui {
     ///
     /// * `Result<Option<Action>>` - An action to be processed or none.
     pub fn update(&mut self, action: Action) -> io::Result<Option<Action>> {
-        match action {
+        match &action {
             Action::FocusComponent(component_name) => {
-                self.focused = Some(component_name);
+                self.focused = Some(component_name.clone());
             }
             Action::UnfocusComponent => {
                 self.focused = None;
             }
+            Action::Key(_, _) => {
+                if self.app_config.hide_mouse_when_typing {
+                    self.hide_cursor = true;
+                }
+            }
+            Action::Mouse(mouse_event) => {
+                let moved = matches!(
+                    mouse_event.kind,
+                    crossterm::event::MouseEventKind::Moved
+                );
+                if moved {
+                    self.hide_cursor = false;
+                }
+            }
             _ => {}
         }
 
         // We can not send the action only to the `CoreWindow` component because
         // the `StatusBar` component needs to know the area to render the size.
-        self.components
-            .iter_mut()
-            .try_fold(None, |acc, (_, component)| {
-                match component.update(action.clone()) {
-                    Ok(Some(action)) => Ok(Some(action)),
-                    Ok(None) => Ok(acc),
-                    Err(e) => Err(e),
-                }
-            })
+        let (result, dirty) =
+            self.components
+                .iter_mut()
+                .try_fold((None, false), |(acc, dirty), (_, component)| {
+                    match component.update(action.clone()) {
+                        Ok((next_action, component_dirty)) => {
+                            Ok((next_action.or(acc), dirty || component_dirty))
+                        }
+                        Err(e) => Err(e),
+                    }
+                })?;
+
+        self.needs_redraw |= dirty;
+        Ok(result)
     }
     /// Render the user interface to the screen.
     ///
@@ -161,10 +238,12 @@ impl Tui {
         frame: &mut ratatui::Frame<'_>,
         area: Rect,
     ) -> Result<(), AppError> {
-        self.components
+        let (_, dirty) = self
+            .components
             .get_mut(&ComponentName::StatusBar)
             .unwrap()
             .update(Action::UpdateArea(area))?;
+        self.needs_redraw |= dirty;
 
         self.components
             .get_mut(&ComponentName::CoreWindow)
@@ -230,6 +309,8 @@ impl Tui {
             })
             .draw(frame, main_layout[2])?;
 
+        self.needs_redraw = false;
+
         Ok(())
     }
 }